@@ -0,0 +1,233 @@
+use serde_json::Value;
+
+use crate::decoder::ParsedMessage;
+use crate::sse::SseBuilder;
+
+/// Which kind of content block is currently open, so block-scoped deltas
+/// (`text_delta`, `thinking_delta`, `input_json_delta`) dispatch to the
+/// right `SseBuilder` call without the header alone telling us.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OpenBlock {
+    None,
+    Text,
+    Thinking,
+    ToolUse,
+}
+
+/// Translates a decoded event-stream of `ParsedMessage`s into the
+/// Anthropic Messages API SSE sequence, dispatching on the `:event-type`
+/// header the way the wire format itself is framed. Keeps enough state
+/// across messages (current content-block index, which kind of block is
+/// open, running token counts) to turn a raw Bedrock/Q binary stream
+/// into a well-formed `message_start` -> `content_block_*` ->
+/// `message_delta`/`message_stop` sequence.
+pub struct EventStreamToSse {
+    block_index: u32,
+    open_block: OpenBlock,
+    input_tokens: u32,
+    output_tokens: u32,
+}
+
+impl Default for EventStreamToSse {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventStreamToSse {
+    pub fn new() -> Self {
+        Self {
+            block_index: 0,
+            open_block: OpenBlock::None,
+            input_tokens: 0,
+            output_tokens: 0,
+        }
+    }
+
+    /// Consumes one decoded frame and returns the formatted SSE events it
+    /// maps to. A recognized frame produces a one-element `Vec`;
+    /// `messageStop`'s single `String` is itself two concatenated SSE
+    /// events (`message_delta` then `message_stop`), since
+    /// `SseBuilder::message_stop` formats both at once rather than
+    /// returning two separate `Vec` entries. A `:message-type`
+    /// of `exception` or `error` is handled before `:event-type` is even
+    /// consulted, since the event-stream spec uses that header to mark a
+    /// frame as out-of-band regardless of what its `:event-type` (if any)
+    /// says — these surface as a single SSE `error` event rather than being
+    /// run through the normal dispatch. Frames with an unrecognized or
+    /// absent `:event-type` produce none.
+    pub fn translate(&mut self, message: &ParsedMessage) -> Vec<String> {
+        match header_str(message, ":message-type") {
+            Some("exception") | Some("error") => return vec![self.on_exception(message)],
+            _ => {}
+        }
+
+        match header_str(message, ":event-type") {
+            Some("messageStart") => self.on_message_start(message),
+            Some("contentBlockStart") => self.on_content_block_start(message),
+            Some("contentBlockDelta") => self.on_content_block_delta(message),
+            Some("contentBlockStop") => self.on_content_block_stop(),
+            Some("messageStop") => self.on_message_stop(message),
+            _ => Vec::new(),
+        }
+    }
+
+    fn on_exception(&mut self, message: &ParsedMessage) -> String {
+        let error_type = header_str(message, ":exception-type").unwrap_or("internal_error");
+        let error_message = payload_str(message, "message").unwrap_or("stream error");
+        SseBuilder::error_event(error_type, error_message).format()
+    }
+
+    fn on_message_start(&mut self, message: &ParsedMessage) -> Vec<String> {
+        let conversation_id = payload_str(message, "conversationId").unwrap_or_default();
+        let model = payload_str(message, "model").unwrap_or_default();
+        self.input_tokens = payload_u32(message, "inputTokens").unwrap_or(0);
+
+        vec![SseBuilder::message_start(conversation_id, model, self.input_tokens).format()]
+    }
+
+    fn on_content_block_start(&mut self, message: &ParsedMessage) -> Vec<String> {
+        if let Some(tool_use_id) = payload_str(message, "toolUseId") {
+            let tool_name = payload_str(message, "name").unwrap_or_default();
+            self.open_block = OpenBlock::ToolUse;
+            vec![SseBuilder::tool_use_start(self.block_index, tool_use_id, tool_name).format()]
+        } else {
+            let block_type = payload_str(message, "contentBlockType").unwrap_or("text");
+            self.open_block = if block_type == "thinking" {
+                OpenBlock::Thinking
+            } else {
+                OpenBlock::Text
+            };
+            vec![SseBuilder::content_block_start(self.block_index, block_type).format()]
+        }
+    }
+
+    fn on_content_block_delta(&mut self, message: &ParsedMessage) -> Vec<String> {
+        match self.open_block {
+            OpenBlock::ToolUse => {
+                let input = payload_str(message, "input").unwrap_or_default();
+                vec![SseBuilder::tool_use_input_delta(self.block_index, input).format()]
+            }
+            OpenBlock::Thinking => {
+                let text = payload_str(message, "text").unwrap_or_default();
+                vec![SseBuilder::content_block_delta(
+                    self.block_index,
+                    text,
+                    "thinking_delta",
+                    "thinking",
+                )
+                .format()]
+            }
+            OpenBlock::Text | OpenBlock::None => {
+                let text = payload_str(message, "text").unwrap_or_default();
+                vec![
+                    SseBuilder::content_block_delta(self.block_index, text, "text_delta", "text")
+                        .format(),
+                ]
+            }
+        }
+    }
+
+    fn on_content_block_stop(&mut self) -> Vec<String> {
+        let event = SseBuilder::content_block_stop(self.block_index).format();
+        self.block_index += 1;
+        self.open_block = OpenBlock::None;
+        vec![event]
+    }
+
+    fn on_message_stop(&mut self, message: &ParsedMessage) -> Vec<String> {
+        let stop_reason = payload_str(message, "stopReason");
+        self.output_tokens = payload_u32(message, "outputTokens").unwrap_or(self.output_tokens);
+        vec![SseBuilder::message_stop(
+            self.input_tokens,
+            self.output_tokens,
+            stop_reason,
+        )]
+    }
+}
+
+fn header_str<'a>(message: &'a ParsedMessage, name: &str) -> Option<&'a str> {
+    message.headers.get(name).and_then(Value::as_str)
+}
+
+fn payload_str<'a>(message: &'a ParsedMessage, field: &str) -> Option<&'a str> {
+    message.payload.as_ref()?.get(field)?.as_str()
+}
+
+fn payload_u32(message: &ParsedMessage, field: &str) -> Option<u32> {
+    message.payload.as_ref()?.get(field)?.as_u64().map(|v| v as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn message(event_type: &str, payload: Value) -> ParsedMessage {
+        let mut headers = HashMap::new();
+        headers.insert(":event-type".to_string(), json!(event_type));
+        ParsedMessage {
+            headers,
+            typed_headers: HashMap::new(),
+            payload: Some(payload),
+            total_length: 0,
+        }
+    }
+
+    #[test]
+    fn message_start_through_message_stop_is_a_well_formed_sequence() {
+        let mut bridge = EventStreamToSse::new();
+
+        let start = bridge.translate(&message(
+            "messageStart",
+            json!({"conversationId": "conv-1", "model": "claude", "inputTokens": 10}),
+        ));
+        assert_eq!(start.len(), 1);
+        assert!(start[0].starts_with("event: message_start\n"));
+
+        let block_start = bridge.translate(&message(
+            "contentBlockStart",
+            json!({"contentBlockType": "text"}),
+        ));
+        assert_eq!(block_start.len(), 1);
+        assert!(block_start[0].starts_with("event: content_block_start\n"));
+
+        let delta = bridge.translate(&message("contentBlockDelta", json!({"text": "hi"})));
+        assert_eq!(delta.len(), 1);
+        assert!(delta[0].contains("text_delta"));
+
+        let block_stop = bridge.translate(&message("contentBlockStop", json!({})));
+        assert_eq!(block_stop.len(), 1);
+        assert!(block_stop[0].starts_with("event: content_block_stop\n"));
+
+        let stop = bridge.translate(&message(
+            "messageStop",
+            json!({"stopReason": "end_turn", "outputTokens": 5}),
+        ));
+        assert_eq!(stop.len(), 1);
+        assert!(stop[0].starts_with("event: message_delta\n"));
+        assert!(stop[0].contains("event: message_stop\n"));
+    }
+
+    #[test]
+    fn message_type_exception_produces_an_error_event_regardless_of_event_type() {
+        let mut bridge = EventStreamToSse::new();
+
+        let mut headers = HashMap::new();
+        headers.insert(":message-type".to_string(), json!("exception"));
+        headers.insert(":exception-type".to_string(), json!("ThrottlingException"));
+        let frame = ParsedMessage {
+            headers,
+            typed_headers: HashMap::new(),
+            payload: Some(json!({"message": "rate limited"})),
+            total_length: 0,
+        };
+
+        let events = bridge.translate(&frame);
+        assert_eq!(events.len(), 1);
+        assert!(events[0].starts_with("event: error\n"));
+        assert!(events[0].contains("ThrottlingException"));
+        assert!(events[0].contains("rate limited"));
+    }
+}
@@ -11,9 +11,65 @@ pub enum DecoderState {
     Stopped,
 }
 
-#[derive(Debug, Clone)]
+/// A single event-stream header value, preserving the exact wire type
+/// instead of collapsing everything into a JSON [`Value`]. `headers` on
+/// [`ParsedMessage`] stays JSON-shaped for existing callers; `typed_headers`
+/// carries this richer view for callers that need to tell a timestamp
+/// from a plain int64, or re-encode a frame faithfully.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeaderValue {
+    Bool(bool),
+    /// Type 2. Signed, matching the wire format — `0xFF` decodes to `-1`,
+    /// not `255`. Earlier versions of this decoder read type 2 as an
+    /// unsigned byte into `headers`; that was a bug, not a format the
+    /// wire actually uses, and both `headers` and `typed_headers` now
+    /// agree on the signed reading.
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    /// Type 8: milliseconds since the Unix epoch, plus the same instant
+    /// rendered as RFC 3339 for display. Intentionally rendered into
+    /// `headers` as an RFC 3339 *string* rather than the raw millis
+    /// `Number` it used to produce — that was the whole point of telling
+    /// a timestamp apart from a plain int64 (type 5), which otherwise
+    /// collapsed to the same `Value::Number`. Callers that need the raw
+    /// integer back should read `typed_headers` instead.
+    Timestamp { millis: i64, rfc3339: String },
+    ByteArray(Vec<u8>),
+    String(String),
+    Uuid([u8; 16]),
+}
+
+impl HeaderValue {
+    /// The JSON-shaped view used by `headers`. See the `Int8` and
+    /// `Timestamp` variant docs above for the two intentional, breaking
+    /// changes in shape this introduces relative to the pre-typed decoder.
+    pub fn to_json(&self) -> Value {
+        match self {
+            HeaderValue::Bool(b) => Value::Bool(*b),
+            HeaderValue::Int8(v) => Value::Number((*v as i64).into()),
+            HeaderValue::Int16(v) => Value::Number((*v as i64).into()),
+            HeaderValue::Int32(v) => Value::Number((*v as i64).into()),
+            HeaderValue::Int64(v) => Value::Number((*v).into()),
+            HeaderValue::Timestamp { rfc3339, .. } => Value::String(rfc3339.clone()),
+            HeaderValue::ByteArray(bytes) => Value::String(hex::encode(bytes)),
+            HeaderValue::String(s) => Value::String(s.clone()),
+            HeaderValue::Uuid(bytes) => Value::String(hex::encode(bytes)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct ParsedMessage {
+    /// JSON projection of every header, via [`HeaderValue::to_json`]. Since
+    /// this chunk introduced [`HeaderValue`], this map's shape changed for
+    /// two types: int8 (type 2) is now signed (`-128..127`) instead of the
+    /// old unsigned `0..255` reading, and timestamps (type 8) render as an
+    /// RFC 3339 string instead of a raw millis `Number`. Both changes are
+    /// intentional and apply here, not only to `typed_headers`.
     pub headers: HashMap<String, Value>,
+    pub typed_headers: HashMap<String, HeaderValue>,
     pub payload: Option<Value>,
     pub total_length: u32,
 }
@@ -24,18 +80,29 @@ pub struct EventStreamDecoder {
     error_count: u32,
     max_errors: u32,
     validate_crc: bool,
+    strict: bool,
+    last_error: Option<ParseError>,
     pub messages_parsed: u64,
     pub crc_errors: u64,
 }
 
 impl EventStreamDecoder {
-    pub fn new(max_errors: u32, validate_crc: bool) -> Self {
+    /// `strict` controls what happens when a header block runs past the
+    /// end of its buffer: in lenient mode (the default used by
+    /// `pyo3`'s constructor) parsing silently stops and returns whatever
+    /// headers were read so far; in strict mode it returns
+    /// [`ParseError::HeaderParseError`] with the offset of the truncation,
+    /// feeding the normal error-count/recovery path instead of producing a
+    /// message with missing headers.
+    pub fn new(max_errors: u32, validate_crc: bool, strict: bool) -> Self {
         Self {
             state: DecoderState::Ready,
             buffer: BytesMut::with_capacity(64 * 1024),
             error_count: 0,
             max_errors,
             validate_crc,
+            strict,
+            last_error: None,
             messages_parsed: 0,
             crc_errors: 0,
         }
@@ -45,6 +112,14 @@ impl EventStreamDecoder {
         self.state
     }
 
+    /// Takes the most recent parse error, if any, clearing it so it is
+    /// surfaced only once. Set whenever `feed` encounters a malformed
+    /// frame and cleared on the next successfully parsed message; most
+    /// useful once `state()` has become `Stopped`, to learn why.
+    pub fn take_last_error(&mut self) -> Option<ParseError> {
+        self.last_error.take()
+    }
+
     pub fn feed(&mut self, data: &[u8]) -> Vec<ParsedMessage> {
         if self.state == DecoderState::Stopped {
             return Vec::new();
@@ -70,12 +145,14 @@ impl EventStreamDecoder {
                 Ok(Some(msg)) => {
                     self.state = DecoderState::Ready;
                     self.error_count = 0;
+                    self.last_error = None;
                     self.messages_parsed += 1;
                     messages.push(msg);
                 }
                 Ok(None) => break,
-                Err(_) => {
+                Err(err) => {
                     self.error_count += 1;
+                    self.last_error = Some(err);
                     if self.error_count >= self.max_errors {
                         self.state = DecoderState::Stopped;
                         break;
@@ -146,10 +223,18 @@ impl EventStreamDecoder {
             message_data[7],
         ]) as usize;
 
-        let headers = parse_headers(&message_data[12..12 + headers_length])?;
-
         let payload_start = 12 + headers_length;
         let payload_end = total_length as usize - 4;
+        if payload_start > payload_end {
+            return Err(ParseError::HeaderParseError(12 + headers_length));
+        }
+
+        let typed_headers = parse_headers(&message_data[12..payload_start], self.strict)?;
+        let headers = typed_headers
+            .iter()
+            .map(|(name, value)| (name.clone(), value.to_json()))
+            .collect();
+
         let payload_data = &message_data[payload_start..payload_end];
 
         let payload = if payload_data.is_empty() {
@@ -165,6 +250,7 @@ impl EventStreamDecoder {
 
         Ok(Some(ParsedMessage {
             headers,
+            typed_headers,
             payload,
             total_length,
         }))
@@ -214,67 +300,110 @@ impl EventStreamDecoder {
         self.buffer.clear();
         self.error_count = 0;
     }
+
+    /// Wraps this decoder and an [`AsyncRead`](futures_io::AsyncRead) source
+    /// into a `futures::Stream` of parsed messages, reading and feeding
+    /// chunks as the stream is polled. See [`crate::stream::EventStream`].
+    #[cfg(feature = "stream")]
+    pub fn into_stream<R: futures_io::AsyncRead + Unpin>(
+        self,
+        reader: R,
+    ) -> crate::stream::EventStream<R> {
+        crate::stream::EventStream::new(self, reader)
+    }
+
+    /// Tokio-flavored equivalent of [`Self::into_stream`], built on
+    /// [`tokio::io::AsyncRead`]. See [`crate::stream::TokioEventStream`].
+    #[cfg(feature = "tokio")]
+    pub fn into_tokio_stream<R: tokio::io::AsyncRead + Unpin>(
+        self,
+        reader: R,
+    ) -> crate::stream::TokioEventStream<R> {
+        crate::stream::TokioEventStream::new(self, reader)
+    }
+}
+
+fn millis_to_rfc3339(millis: i64) -> String {
+    chrono::DateTime::<chrono::Utc>::from_timestamp_millis(millis)
+        .map(|dt| dt.to_rfc3339())
+        .unwrap_or_else(|| millis.to_string())
 }
 
-fn parse_headers(data: &[u8]) -> Result<HashMap<String, Value>, ParseError> {
+/// Parses the header block of a single frame. In lenient mode a header
+/// that runs past the end of `data` silently stops parsing (matching the
+/// historical behavior: a corrupt tail yields a partial header map rather
+/// than an error). In strict mode the same condition instead returns
+/// [`ParseError::HeaderParseError`] with the offset where the header was
+/// found to be truncated.
+fn parse_headers(data: &[u8], strict: bool) -> Result<HashMap<String, HeaderValue>, ParseError> {
+    macro_rules! truncated {
+        ($offset:expr) => {
+            if strict {
+                return Err(ParseError::HeaderParseError($offset));
+            } else {
+                break;
+            }
+        };
+    }
+
     let mut headers = HashMap::new();
     let mut offset = 0;
 
     while offset < data.len() {
         if offset >= data.len() {
-            break;
+            truncated!(offset);
         }
         let name_length = data[offset] as usize;
         offset += 1;
 
         if offset + name_length > data.len() {
-            break;
+            truncated!(offset);
         }
         let name = String::from_utf8(data[offset..offset + name_length].to_vec())
             .map_err(|e| ParseError::Utf8Error(e))?;
         offset += name_length;
 
         if offset >= data.len() {
-            break;
+            truncated!(offset);
         }
         let value_type = data[offset];
         offset += 1;
 
         let value = match value_type {
-            0 => Value::Bool(true),
-            1 => Value::Bool(false),
+            0 => HeaderValue::Bool(true),
+            1 => HeaderValue::Bool(false),
             2 => {
                 if offset >= data.len() {
-                    break;
+                    truncated!(offset);
                 }
-                let v = data[offset] as i64;
+                let v = data[offset] as i8;
                 offset += 1;
-                Value::Number(v.into())
+                HeaderValue::Int8(v)
             }
             3 => {
                 if offset + 2 > data.len() {
-                    break;
+                    truncated!(offset);
                 }
-                let v = i16::from_be_bytes([data[offset], data[offset + 1]]) as i64;
+                let v = i16::from_be_bytes([data[offset], data[offset + 1]]);
                 offset += 2;
-                Value::Number(v.into())
+                HeaderValue::Int16(v)
             }
             4 => {
                 if offset + 4 > data.len() {
-                    break;
+                    truncated!(offset);
                 }
                 let v = i32::from_be_bytes([
                     data[offset],
                     data[offset + 1],
                     data[offset + 2],
                     data[offset + 3],
-                ]) as i64;
+                ]);
                 offset += 4;
-                Value::Number(v.into())
+                HeaderValue::Int32(v)
             }
-            5 | 8 => {
+            5 => {
                 if offset + 8 > data.len() {
-                    break;
+                    truncated!(offset);
                 }
                 let v = i64::from_be_bytes([
                     data[offset],
@@ -287,33 +416,54 @@ fn parse_headers(data: &[u8]) -> Result<HashMap<String, Value>, ParseError> {
                     data[offset + 7],
                 ]);
                 offset += 8;
-                Value::Number(v.into())
+                HeaderValue::Int64(v)
+            }
+            8 => {
+                if offset + 8 > data.len() {
+                    truncated!(offset);
+                }
+                let millis = i64::from_be_bytes([
+                    data[offset],
+                    data[offset + 1],
+                    data[offset + 2],
+                    data[offset + 3],
+                    data[offset + 4],
+                    data[offset + 5],
+                    data[offset + 6],
+                    data[offset + 7],
+                ]);
+                offset += 8;
+                HeaderValue::Timestamp {
+                    millis,
+                    rfc3339: millis_to_rfc3339(millis),
+                }
             }
             6 | 7 => {
                 if offset + 2 > data.len() {
-                    break;
+                    truncated!(offset);
                 }
                 let value_length =
                     u16::from_be_bytes([data[offset], data[offset + 1]]) as usize;
                 offset += 2;
                 if offset + value_length > data.len() {
-                    break;
+                    truncated!(offset);
                 }
                 let raw = &data[offset..offset + value_length];
                 offset += value_length;
                 if value_type == 7 {
-                    Value::String(String::from_utf8_lossy(raw).into_owned())
+                    HeaderValue::String(String::from_utf8_lossy(raw).into_owned())
                 } else {
-                    Value::String(hex::encode(raw))
+                    HeaderValue::ByteArray(raw.to_vec())
                 }
             }
             9 => {
                 if offset + 16 > data.len() {
-                    break;
+                    truncated!(offset);
                 }
-                let uuid_bytes = &data[offset..offset + 16];
+                let mut uuid_bytes = [0u8; 16];
+                uuid_bytes.copy_from_slice(&data[offset..offset + 16]);
                 offset += 16;
-                Value::String(hex::encode(uuid_bytes))
+                HeaderValue::Uuid(uuid_bytes)
             }
             _ => return Err(ParseError::InvalidHeaderType(value_type)),
         };
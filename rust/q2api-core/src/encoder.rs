@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+
+use bytes::{BufMut, BytesMut};
+use serde_json::Value;
+
+use crate::decoder::HeaderValue;
+use crate::error::EncodeError;
+
+/// Serializes headers and an optional payload into the binary
+/// `vnd.amazon.eventstream` wire format that [`crate::decoder::EventStreamDecoder`]
+/// consumes: a 4-byte total length, 4-byte headers length, a CRC32C of
+/// those 8 prelude bytes, the encoded headers, the payload, and a
+/// trailing CRC32C over everything but itself. Rejects a frame whose total
+/// length would exceed the 16 MiB ceiling the decoder enforces, rather
+/// than silently producing a frame the decoder can never parse back.
+pub struct EventStreamEncoder;
+
+impl EventStreamEncoder {
+    /// Encodes `headers` and `payload` into a single on-wire frame.
+    pub fn encode(
+        headers: &HashMap<String, Value>,
+        payload: Option<&[u8]>,
+    ) -> Result<Vec<u8>, EncodeError> {
+        let mut encoded_headers = BytesMut::new();
+        for (name, value) in headers {
+            encode_header(&mut encoded_headers, name, value)?;
+        }
+
+        Self::assemble(encoded_headers, payload.unwrap_or(&[]))
+    }
+
+    /// Convenience wrapper that serializes `payload` to JSON before encoding.
+    pub fn encode_json(
+        headers: &HashMap<String, Value>,
+        payload: &Value,
+    ) -> Result<Vec<u8>, EncodeError> {
+        let payload_bytes = serde_json::to_vec(payload)?;
+        Self::encode(headers, Some(&payload_bytes))
+    }
+
+    /// Like [`Self::encode`], but takes [`HeaderValue`]s instead of JSON
+    /// `Value`s so every wire type — including byte-array (6), timestamp
+    /// (8), and uuid (9), which the plain JSON view can't tell apart from
+    /// a string or an int64 — round-trips exactly as
+    /// [`crate::decoder::EventStreamDecoder`] decoded it.
+    pub fn encode_typed(
+        headers: &HashMap<String, HeaderValue>,
+        payload: Option<&[u8]>,
+    ) -> Result<Vec<u8>, EncodeError> {
+        let mut encoded_headers = BytesMut::new();
+        for (name, value) in headers {
+            encode_typed_header(&mut encoded_headers, name, value)?;
+        }
+
+        Self::assemble(encoded_headers, payload.unwrap_or(&[]))
+    }
+
+    fn assemble(encoded_headers: BytesMut, payload: &[u8]) -> Result<Vec<u8>, EncodeError> {
+        let headers_length = encoded_headers.len() as u32;
+        let total_length = 12 + headers_length + payload.len() as u32 + 4;
+
+        if total_length > 16 * 1024 * 1024 {
+            return Err(EncodeError::FrameTooLarge(total_length));
+        }
+
+        let mut message = BytesMut::with_capacity(total_length as usize);
+        message.put_u32(total_length);
+        message.put_u32(headers_length);
+        message.put_u32(crc32c::crc32c(&message));
+
+        message.extend_from_slice(&encoded_headers);
+        message.extend_from_slice(payload);
+        message.put_u32(crc32c::crc32c(&message));
+
+        Ok(message.to_vec())
+    }
+}
+
+fn encode_header_name(buf: &mut BytesMut, name: &str) -> Result<(), EncodeError> {
+    if name.len() > u8::MAX as usize {
+        return Err(EncodeError::HeaderNameTooLong(name.to_string()));
+    }
+    buf.put_u8(name.len() as u8);
+    buf.extend_from_slice(name.as_bytes());
+    Ok(())
+}
+
+fn encode_header(buf: &mut BytesMut, name: &str, value: &Value) -> Result<(), EncodeError> {
+    encode_header_name(buf, name)?;
+
+    match value {
+        Value::Bool(true) => buf.put_u8(0),
+        Value::Bool(false) => buf.put_u8(1),
+        Value::Number(n) => {
+            let v = n.as_i64().ok_or_else(|| EncodeError::UnsupportedHeaderValue {
+                name: name.to_string(),
+                value: value.to_string(),
+            })?;
+            encode_int_header(buf, v);
+        }
+        Value::String(s) => {
+            if s.len() > u16::MAX as usize {
+                return Err(EncodeError::HeaderValueTooLong(name.to_string()));
+            }
+            buf.put_u8(7);
+            buf.put_u16(s.len() as u16);
+            buf.extend_from_slice(s.as_bytes());
+        }
+        other => {
+            return Err(EncodeError::UnsupportedHeaderValue {
+                name: name.to_string(),
+                value: other.to_string(),
+            })
+        }
+    }
+
+    Ok(())
+}
+
+/// Picks the narrowest int8/16/32/64 tag that losslessly fits `v`,
+/// mirroring how the AWS wire format favors compact header encodings.
+fn encode_int_header(buf: &mut BytesMut, v: i64) {
+    if let Ok(v8) = i8::try_from(v) {
+        buf.put_u8(2);
+        buf.put_i8(v8);
+    } else if let Ok(v16) = i16::try_from(v) {
+        buf.put_u8(3);
+        buf.put_i16(v16);
+    } else if let Ok(v32) = i32::try_from(v) {
+        buf.put_u8(4);
+        buf.put_i32(v32);
+    } else {
+        buf.put_u8(5);
+        buf.put_i64(v);
+    }
+}
+
+fn encode_typed_header(buf: &mut BytesMut, name: &str, value: &HeaderValue) -> Result<(), EncodeError> {
+    encode_header_name(buf, name)?;
+
+    match value {
+        HeaderValue::Bool(true) => buf.put_u8(0),
+        HeaderValue::Bool(false) => buf.put_u8(1),
+        HeaderValue::Int8(v) => {
+            buf.put_u8(2);
+            buf.put_i8(*v);
+        }
+        HeaderValue::Int16(v) => {
+            buf.put_u8(3);
+            buf.put_i16(*v);
+        }
+        HeaderValue::Int32(v) => {
+            buf.put_u8(4);
+            buf.put_i32(*v);
+        }
+        HeaderValue::Int64(v) => {
+            buf.put_u8(5);
+            buf.put_i64(*v);
+        }
+        HeaderValue::ByteArray(bytes) => {
+            if bytes.len() > u16::MAX as usize {
+                return Err(EncodeError::HeaderValueTooLong(name.to_string()));
+            }
+            buf.put_u8(6);
+            buf.put_u16(bytes.len() as u16);
+            buf.extend_from_slice(bytes);
+        }
+        HeaderValue::String(s) => {
+            if s.len() > u16::MAX as usize {
+                return Err(EncodeError::HeaderValueTooLong(name.to_string()));
+            }
+            buf.put_u8(7);
+            buf.put_u16(s.len() as u16);
+            buf.extend_from_slice(s.as_bytes());
+        }
+        HeaderValue::Timestamp { millis, .. } => {
+            buf.put_u8(8);
+            buf.put_i64(*millis);
+        }
+        HeaderValue::Uuid(bytes) => {
+            buf.put_u8(9);
+            buf.extend_from_slice(bytes);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::EventStreamDecoder;
+    use serde_json::json;
+
+    #[test]
+    fn round_trips_through_the_decoder() {
+        let mut headers = HashMap::new();
+        headers.insert("bool-true".to_string(), json!(true));
+        headers.insert("bool-false".to_string(), json!(false));
+        headers.insert("int8".to_string(), json!(42));
+        headers.insert("int16".to_string(), json!(1_000));
+        headers.insert("int32".to_string(), json!(100_000));
+        headers.insert("int64".to_string(), json!(5_000_000_000i64));
+        headers.insert("name".to_string(), json!("q2api"));
+
+        let payload = json!({"ok": true, "n": 7});
+        let frame = EventStreamEncoder::encode_json(&headers, &payload).unwrap();
+
+        let mut decoder = EventStreamDecoder::new(3, true, false);
+        let messages = decoder.feed(&frame);
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].headers, headers);
+        assert_eq!(messages[0].payload, Some(payload));
+        assert_eq!(messages[0].total_length as usize, frame.len());
+    }
+
+    #[test]
+    fn typed_round_trips_byte_array_timestamp_and_uuid() {
+        let mut headers = HashMap::new();
+        headers.insert(
+            "chunk".to_string(),
+            HeaderValue::ByteArray(vec![0xde, 0xad, 0xbe, 0xef]),
+        );
+        headers.insert(
+            "sent-at".to_string(),
+            HeaderValue::Timestamp {
+                millis: 1_700_000_000_000,
+                rfc3339: String::new(), // not read by the encoder; only `millis` is on the wire
+            },
+        );
+        headers.insert(
+            "trace-id".to_string(),
+            HeaderValue::Uuid([
+                0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+                0x0e, 0x0f, 0x10,
+            ]),
+        );
+
+        let frame = EventStreamEncoder::encode_typed(&headers, None).unwrap();
+
+        let mut decoder = EventStreamDecoder::new(3, true, false);
+        let messages = decoder.feed(&frame);
+
+        assert_eq!(messages.len(), 1);
+        let typed = &messages[0].typed_headers;
+        assert_eq!(
+            typed.get("chunk"),
+            Some(&HeaderValue::ByteArray(vec![0xde, 0xad, 0xbe, 0xef]))
+        );
+        assert_eq!(
+            typed.get("trace-id"),
+            Some(&HeaderValue::Uuid([
+                0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+                0x0e, 0x0f, 0x10,
+            ]))
+        );
+        match typed.get("sent-at") {
+            Some(HeaderValue::Timestamp { millis, .. }) => assert_eq!(*millis, 1_700_000_000_000),
+            other => panic!("expected a Timestamp header, got {other:?}"),
+        }
+    }
+}
@@ -28,4 +28,25 @@ pub enum ParseError {
 
     #[error("JSON parse error: {0}")]
     JsonError(#[from] serde_json::Error),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[derive(Error, Debug)]
+pub enum EncodeError {
+    #[error("header name {0:?} is longer than 255 bytes")]
+    HeaderNameTooLong(String),
+
+    #[error("header {0:?} value is longer than 65535 bytes")]
+    HeaderValueTooLong(String),
+
+    #[error("header {name:?} has a value type the encoder cannot represent: {value}")]
+    UnsupportedHeaderValue { name: String, value: String },
+
+    #[error("encoded frame would be {0} bytes, over the 16 MiB limit the decoder enforces")]
+    FrameTooLarge(u32),
+
+    #[error("JSON encode error: {0}")]
+    JsonError(#[from] serde_json::Error),
 }
@@ -1,10 +1,25 @@
+mod bridge;
 mod decoder;
+mod encoder;
 mod error;
 mod sse;
+mod vectors;
 
-pub use decoder::{DecoderState, EventStreamDecoder, ParsedMessage};
-pub use error::ParseError;
+pub use bridge::EventStreamToSse;
+pub use decoder::{DecoderState, EventStreamDecoder, HeaderValue, ParsedMessage};
+pub use encoder::EventStreamEncoder;
+pub use error::{EncodeError, ParseError};
 pub use sse::{SseBuilder, SseEvent};
+pub use vectors::{builtin_corpus, load_hex_corpus, run_vectors, TestVector, VectorResult};
+
+#[cfg(any(feature = "stream", feature = "tokio"))]
+mod stream;
+
+#[cfg(feature = "stream")]
+pub use stream::EventStream;
+
+#[cfg(feature = "tokio")]
+pub use stream::TokioEventStream;
 
 #[cfg(feature = "python")]
 mod python;
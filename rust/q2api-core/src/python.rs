@@ -33,10 +33,10 @@ pub struct PyEventStreamDecoder {
 #[pymethods]
 impl PyEventStreamDecoder {
     #[new]
-    #[pyo3(signature = (max_errors=3, validate_crc=true))]
-    fn new(max_errors: u32, validate_crc: bool) -> Self {
+    #[pyo3(signature = (max_errors=3, validate_crc=true, strict=false))]
+    fn new(max_errors: u32, validate_crc: bool, strict: bool) -> Self {
         Self {
-            inner: EventStreamDecoder::new(max_errors, validate_crc),
+            inner: EventStreamDecoder::new(max_errors, validate_crc, strict),
         }
     }
 
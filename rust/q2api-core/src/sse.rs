@@ -119,6 +119,16 @@ impl SseBuilder {
         }
     }
 
+    pub fn error_event(error_type: &str, message: &str) -> SseEvent {
+        SseEvent {
+            event_type: "error".to_string(),
+            data: json!({
+                "type": "error",
+                "error": {"type": error_type, "message": message}
+            }),
+        }
+    }
+
     pub fn tool_use_input_delta(index: u32, input_json_delta: &str) -> SseEvent {
         SseEvent {
             event_type: "content_block_delta".to_string(),
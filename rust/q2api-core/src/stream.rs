@@ -0,0 +1,139 @@
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+
+use crate::decoder::{DecoderState, EventStreamDecoder, ParsedMessage};
+use crate::error::ParseError;
+
+const READ_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Adapts an [`EventStreamDecoder`] and an [`AsyncRead`](futures_io::AsyncRead)
+/// source into a `futures::Stream`, so callers can plug the decoder
+/// directly into a hyper/reqwest response body instead of driving `feed`
+/// by hand. Mirrors [`EventStreamDecoder::feed`]: chunks are read into an
+/// internal buffer and handed to the same parsing logic, with partial
+/// frames carried across `poll_next` calls.
+///
+/// Per-frame decode errors are swallowed by `feed` (it just retries via
+/// `try_recover`), so they never appear mid-stream here either. Only once
+/// the decoder gives up — `state()` becomes `Stopped` — does the stream
+/// yield one final `Err` with the error that caused it, taken via
+/// [`EventStreamDecoder::take_last_error`], before ending with `None`. A
+/// clean EOF therefore ends the stream with `None` directly, while a
+/// corrupt-stream abort ends it with `Some(Err(_))` then `None`.
+#[cfg(feature = "stream")]
+pub struct EventStream<R> {
+    decoder: EventStreamDecoder,
+    reader: R,
+    read_buf: Box<[u8]>,
+    pending: VecDeque<ParsedMessage>,
+}
+
+#[cfg(feature = "stream")]
+impl<R> EventStream<R> {
+    pub(crate) fn new(decoder: EventStreamDecoder, reader: R) -> Self {
+        Self {
+            decoder,
+            reader,
+            read_buf: vec![0u8; READ_CHUNK_SIZE].into_boxed_slice(),
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+#[cfg(feature = "stream")]
+impl<R: futures_io::AsyncRead + Unpin> Stream for EventStream<R> {
+    type Item = Result<ParsedMessage, ParseError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use futures_io::AsyncRead;
+
+        let this = self.get_mut();
+
+        loop {
+            if let Some(msg) = this.pending.pop_front() {
+                return Poll::Ready(Some(Ok(msg)));
+            }
+
+            if this.decoder.state() == DecoderState::Stopped {
+                if let Some(err) = this.decoder.take_last_error() {
+                    return Poll::Ready(Some(Err(err)));
+                }
+                return Poll::Ready(None);
+            }
+
+            match Pin::new(&mut this.reader).poll_read(cx, &mut this.read_buf) {
+                Poll::Ready(Ok(0)) => return Poll::Ready(None),
+                Poll::Ready(Ok(n)) => {
+                    this.pending.extend(this.decoder.feed(&this.read_buf[..n]));
+                }
+                Poll::Ready(Err(err)) => return Poll::Ready(Some(Err(ParseError::Io(err)))),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Tokio-flavored counterpart of [`EventStream`], built on
+/// [`tokio::io::AsyncRead`] so the decoder can be driven directly from a
+/// `TcpStream`, a hyper body wrapped with `tokio_util::io::StreamReader`,
+/// or any other tokio-native reader without an extra adapter crate. Reports
+/// terminal decode errors the same way — see [`EventStream`]'s doc comment.
+#[cfg(feature = "tokio")]
+pub struct TokioEventStream<R> {
+    decoder: EventStreamDecoder,
+    reader: R,
+    read_buf: Box<[u8]>,
+    pending: VecDeque<ParsedMessage>,
+}
+
+#[cfg(feature = "tokio")]
+impl<R> TokioEventStream<R> {
+    pub(crate) fn new(decoder: EventStreamDecoder, reader: R) -> Self {
+        Self {
+            decoder,
+            reader,
+            read_buf: vec![0u8; READ_CHUNK_SIZE].into_boxed_slice(),
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<R: tokio::io::AsyncRead + Unpin> Stream for TokioEventStream<R> {
+    type Item = Result<ParsedMessage, ParseError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use tokio::io::{AsyncRead, ReadBuf};
+
+        let this = self.get_mut();
+
+        loop {
+            if let Some(msg) = this.pending.pop_front() {
+                return Poll::Ready(Some(Ok(msg)));
+            }
+
+            if this.decoder.state() == DecoderState::Stopped {
+                if let Some(err) = this.decoder.take_last_error() {
+                    return Poll::Ready(Some(Err(err)));
+                }
+                return Poll::Ready(None);
+            }
+
+            let mut read_buf = ReadBuf::new(&mut this.read_buf);
+            match Pin::new(&mut this.reader).poll_read(cx, &mut read_buf) {
+                Poll::Ready(Ok(())) => {
+                    let n = read_buf.filled().len();
+                    if n == 0 {
+                        return Poll::Ready(None);
+                    }
+                    this.pending.extend(this.decoder.feed(read_buf.filled()));
+                }
+                Poll::Ready(Err(err)) => return Poll::Ready(Some(Err(ParseError::Io(err)))),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+use std::io::{BufRead, Read};
+
+use serde_json::json;
+
+use crate::decoder::{EventStreamDecoder, HeaderValue, ParsedMessage};
+use crate::encoder::EventStreamEncoder;
+use crate::error::EncodeError;
+
+/// One row of a conformance corpus: a single on-wire frame (as hex) plus
+/// the outcome a fresh [`EventStreamDecoder`] is expected to produce for
+/// it. `expected` may be left empty for a passing vector whose exact
+/// message contents aren't worth pinning down; `should_fail` is always
+/// checked. `validate_crc` is almost always `true`; set it to `false` to
+/// exercise a vector that only matters with CRC checking disabled, such
+/// as a `headers_length` that runs past the frame body (CRC validation
+/// would normally catch a frame corrupted that way first).
+#[derive(Debug, Clone)]
+pub struct TestVector {
+    pub input_hex: String,
+    pub expected: Vec<ParsedMessage>,
+    pub should_fail: bool,
+    pub validate_crc: bool,
+    pub desc: String,
+}
+
+/// Outcome of running a single [`TestVector`] through a fresh decoder.
+#[derive(Debug)]
+pub struct VectorResult {
+    pub desc: String,
+    pub passed: bool,
+    pub messages: Vec<ParsedMessage>,
+}
+
+/// Feeds every vector through its own `EventStreamDecoder` (so one
+/// corrupt frame can't poison a later vector's state) and reports
+/// whether the observed outcome matched what the vector declared.
+/// `max_errors` should be at least 2 to give the resynchronization
+/// vector in [`builtin_corpus`] room to recover before the decoder stops.
+pub fn run_vectors(vectors: &[TestVector], max_errors: u32, strict: bool) -> Vec<VectorResult> {
+    vectors
+        .iter()
+        .map(|vector| run_vector(vector, max_errors, strict))
+        .collect()
+}
+
+fn run_vector(vector: &TestVector, max_errors: u32, strict: bool) -> VectorResult {
+    let Ok(bytes) = hex::decode(vector.input_hex.trim()) else {
+        return VectorResult {
+            desc: vector.desc.clone(),
+            passed: false,
+            messages: Vec::new(),
+        };
+    };
+
+    let mut decoder = EventStreamDecoder::new(max_errors, vector.validate_crc, strict);
+    let messages = decoder.feed(&bytes);
+
+    let passed = if vector.should_fail {
+        messages.is_empty()
+    } else {
+        !messages.is_empty() && (vector.expected.is_empty() || messages == vector.expected)
+    };
+
+    VectorResult {
+        desc: vector.desc.clone(),
+        passed,
+        messages,
+    }
+}
+
+/// Loads a newline-delimited hex corpus: blank lines and lines starting
+/// with `#` are skipped, an optional leading `FAIL ` marks a frame that
+/// is expected to yield no messages, and the rest of the line is the
+/// hex-encoded frame. This loader only knows pass/fail, not exact
+/// message contents — pair it with a hand-authored [`TestVector`] (see
+/// [`builtin_corpus`]) when the expected headers/payload matter.
+pub fn load_hex_corpus<R: Read>(reader: R) -> std::io::Result<Vec<TestVector>> {
+    let reader = std::io::BufReader::new(reader);
+    let mut vectors = Vec::new();
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (should_fail, input_hex) = match line.strip_prefix("FAIL ") {
+            Some(rest) => (true, rest.trim().to_string()),
+            None => (false, line.to_string()),
+        };
+
+        vectors.push(TestVector {
+            input_hex,
+            expected: Vec::new(),
+            should_fail,
+            validate_crc: true,
+            desc: format!("line {}", i + 1),
+        });
+    }
+
+    Ok(vectors)
+}
+
+/// A small hand-authored corpus covering the boundary cases the framing
+/// and CRC logic only implicitly handle: the minimum 16-byte frame, the
+/// 16 MiB max-length rejection, prelude/message CRC mismatches on a
+/// non-empty frame, every header value type (0-9), and mid-stream
+/// resynchronization via `try_recover`.
+///
+/// Builds its frames with the encoder rather than embedding raw hex, but
+/// returns `Err` instead of panicking if that ever fails (the inputs are
+/// fixed, so in practice this always succeeds).
+pub fn builtin_corpus() -> Result<Vec<TestVector>, EncodeError> {
+    let mut vectors = Vec::new();
+
+    // Minimum valid frame: no headers, no payload (12-byte prelude + 4-byte
+    // trailing CRC = 16 bytes total, the smallest a frame can be).
+    let min_frame = EventStreamEncoder::encode(&HashMap::new(), None)?;
+    vectors.push(TestVector {
+        input_hex: hex::encode(&min_frame),
+        expected: vec![ParsedMessage {
+            headers: HashMap::new(),
+            typed_headers: HashMap::new(),
+            payload: None,
+            total_length: min_frame.len() as u32,
+        }],
+        should_fail: false,
+        validate_crc: true,
+        desc: "minimum 16-byte frame with no headers or payload".to_string(),
+    });
+
+    // The bool/int/string header value types (0, 1, 2-5, 7), round-tripped
+    // through the JSON-keyed encoder.
+    let mut headers = HashMap::new();
+    headers.insert("flag".to_string(), json!(true));
+    headers.insert("off".to_string(), json!(false));
+    headers.insert("small".to_string(), json!(7));
+    headers.insert("medium".to_string(), json!(30_000));
+    headers.insert("large".to_string(), json!(2_000_000_000i64));
+    headers.insert("huge".to_string(), json!(9_000_000_000i64));
+    headers.insert("name".to_string(), json!("q2api"));
+    let bool_int_string_frame = EventStreamEncoder::encode_json(&headers, &json!({"n": 1}))?;
+    vectors.push(TestVector {
+        input_hex: hex::encode(&bool_int_string_frame),
+        // typed_headers isn't pinned down here (it's a richer view derived
+        // from the same bytes); should_fail=false is the meaningful check.
+        expected: Vec::new(),
+        should_fail: false,
+        validate_crc: true,
+        desc: "bool(0/1), int8/16/32/64(2-5), and string(7) header value types".to_string(),
+    });
+
+    // The byte-array(6), timestamp(8), and uuid(9) header value types that
+    // only the typed encode path (and the typed decoder view) can tell
+    // apart from a plain string or int64.
+    let mut typed_headers = HashMap::new();
+    typed_headers.insert(
+        "chunk".to_string(),
+        HeaderValue::ByteArray(vec![0xde, 0xad, 0xbe, 0xef]),
+    );
+    typed_headers.insert(
+        "sent-at".to_string(),
+        HeaderValue::Timestamp {
+            millis: 1_700_000_000_000,
+            rfc3339: String::new(),
+        },
+    );
+    typed_headers.insert(
+        "trace-id".to_string(),
+        HeaderValue::Uuid([
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+            0x0f, 0x10,
+        ]),
+    );
+    let typed_frame = EventStreamEncoder::encode_typed(&typed_headers, None)?;
+    vectors.push(TestVector {
+        input_hex: hex::encode(&typed_frame),
+        expected: Vec::new(),
+        should_fail: false,
+        validate_crc: true,
+        desc: "byte-array(6), timestamp(8), and uuid(9) header value types".to_string(),
+    });
+
+    // Frame declaring a length one past the 16 MiB ceiling: rejected before
+    // the buffer even needs to hold the whole frame.
+    let mut oversized = Vec::new();
+    oversized.extend_from_slice(&(16 * 1024 * 1024 + 1u32).to_be_bytes());
+    oversized.extend_from_slice(&[0u8; 8]);
+    vectors.push(TestVector {
+        input_hex: hex::encode(&oversized),
+        expected: Vec::new(),
+        should_fail: true,
+        validate_crc: true,
+        desc: "total length one past the 16 MiB ceiling is rejected".to_string(),
+    });
+
+    // Prelude CRC mismatch on a non-empty (headers + payload) frame: flip a
+    // byte of the prelude CRC itself.
+    let mut bad_prelude_crc = bool_int_string_frame.clone();
+    bad_prelude_crc[8] ^= 0xff;
+    vectors.push(TestVector {
+        input_hex: hex::encode(&bad_prelude_crc),
+        expected: Vec::new(),
+        should_fail: true,
+        validate_crc: true,
+        desc: "prelude CRC mismatch on a non-empty frame".to_string(),
+    });
+
+    // Message CRC mismatch on the same non-empty frame: flip a byte of the
+    // trailing message CRC.
+    let mut bad_message_crc = bool_int_string_frame.clone();
+    let last = bad_message_crc.len() - 1;
+    bad_message_crc[last] ^= 0xff;
+    vectors.push(TestVector {
+        input_hex: hex::encode(&bad_message_crc),
+        expected: Vec::new(),
+        should_fail: true,
+        validate_crc: true,
+        desc: "message CRC mismatch on a non-empty frame".to_string(),
+    });
+
+    // One junk byte ahead of an otherwise-valid frame: `try_recover` should
+    // resynchronize and still yield the frame.
+    let mut resync = vec![0xffu8];
+    resync.extend_from_slice(&min_frame);
+    vectors.push(TestVector {
+        input_hex: hex::encode(&resync),
+        expected: vec![ParsedMessage {
+            headers: HashMap::new(),
+            typed_headers: HashMap::new(),
+            payload: None,
+            total_length: min_frame.len() as u32,
+        }],
+        should_fail: false,
+        validate_crc: true,
+        desc: "mid-stream resynchronization past one junk byte".to_string(),
+    });
+
+    // With CRC validation off (a supported constructor mode, reachable from
+    // the pyo3 bindings), a `headers_length` that runs past the frame body
+    // must still be rejected cleanly rather than panicking on an
+    // out-of-range slice: total_length=20 (a 4-byte body) but
+    // headers_length=1000 claims far more than fits before the trailing CRC.
+    let mut bad_headers_length = Vec::new();
+    bad_headers_length.extend_from_slice(&20u32.to_be_bytes()); // total_length
+    bad_headers_length.extend_from_slice(&1000u32.to_be_bytes()); // headers_length
+    bad_headers_length.extend_from_slice(&[0u8; 12]); // unchecked prelude CRC + padding to total_length
+    vectors.push(TestVector {
+        input_hex: hex::encode(&bad_headers_length),
+        expected: Vec::new(),
+        should_fail: true,
+        validate_crc: false,
+        desc: "headers_length running past the frame body is rejected, not a panic".to_string(),
+    });
+
+    Ok(vectors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_corpus_is_conformant() {
+        let corpus = builtin_corpus().expect("builtin corpus encodes cleanly");
+        let results = run_vectors(&corpus, 2, false);
+
+        for result in &results {
+            assert!(result.passed, "vector failed: {}", result.desc);
+        }
+    }
+}